@@ -1,106 +1,391 @@
 use std::io;
+use std::collections::VecDeque;
 use std::time::{Instant, Duration};
 use std::error::Error;
 
+use regex::Regex;
+
 use ratatui::{
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
-    layout::{Constraint, Layout, Direction},
+    layout::{Constraint, Layout, Direction, Rect},
     style::{
         Style, Stylize, Color, Modifier,
         palette::tailwind::{BLUE, GREEN, SLATE},
     },
+    symbols::Marker,
     text::Line,
     widgets::{
         Block, List, Paragraph, ListItem, ListState,
         Bar, BarChart, BarGroup,
+        Axis, Chart, Dataset, GraphType,
+        Clear, Row, Table, TableState,
     },
     DefaultTerminal, Frame,
 };
 
+use crate::core::config::{Config, LayoutNode, Widget};
 use crate::core::cpu::CPUData;
+use crate::core::disk::{DiskData, DiskUsage};
 use crate::core::mem::MemData;
+use crate::core::net::NetData;
+use crate::core::process::{ProcessCollector, ProcessData, ProcessSorting};
 use crate::core::server::ServerState;
+use crate::core::temp::{TempData, TempReading, TemperatureType};
+
+/// Which pane currently receives navigation and search input.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Servers,
+    Processes,
+}
+
+/// Incremental search over the focused list, mirroring bottom's
+/// `AppSearchState`: the raw query plus cursor position, its compiled regex,
+/// and whether the query is blank or failed to compile.
+struct SearchState {
+    /// Whether the search box is currently capturing keystrokes.
+    active: bool,
+    query: String,
+    /// Cursor position as a character offset into `query`.
+    cursor: usize,
+    regex: Option<Regex>,
+    is_blank_search: bool,
+    is_invalid_search: bool,
+}
+
+impl SearchState {
+    fn new() -> Self {
+        Self {
+            active: false,
+            query: String::new(),
+            cursor: 0,
+            regex: None,
+            is_blank_search: true,
+            is_invalid_search: false,
+        }
+    }
+
+    /// Recompile the regex and refresh the blank/invalid flags after an edit.
+    fn recompile(&mut self) {
+        if self.query.is_empty() {
+            self.is_blank_search = true;
+            self.is_invalid_search = false;
+            self.regex = None;
+            return;
+        }
+
+        self.is_blank_search = false;
+        match Regex::new(&self.query) {
+            Ok(regex) => {
+                self.regex = Some(regex);
+                self.is_invalid_search = false;
+            }
+            Err(_) => {
+                self.regex = None;
+                self.is_invalid_search = true;
+            }
+        }
+    }
+
+    /// Whether `text` passes the filter. A blank or invalid query matches
+    /// everything, so the list is shown in full rather than cleared.
+    fn matches(&self, text: &str) -> bool {
+        match &self.regex {
+            Some(regex) => regex.is_match(text),
+            None => true,
+        }
+    }
+
+    fn byte_index(&self) -> usize {
+        self.query
+            .char_indices()
+            .nth(self.cursor)
+            .map(|(i, _)| i)
+            .unwrap_or(self.query.len())
+    }
+
+    fn insert(&mut self, c: char) {
+        let index = self.byte_index();
+        self.query.insert(index, c);
+        self.cursor += 1;
+        self.recompile();
+    }
+
+    fn backspace(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        let index = self
+            .query
+            .char_indices()
+            .nth(self.cursor - 1)
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        self.query.remove(index);
+        self.cursor -= 1;
+        self.recompile();
+    }
+
+    fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    fn move_right(&mut self) {
+        if self.cursor < self.query.chars().count() {
+            self.cursor += 1;
+        }
+    }
+
+    /// Discard the query and leave search mode, restoring the full list.
+    fn cancel(&mut self) {
+        self.query.clear();
+        self.cursor = 0;
+        self.active = false;
+        self.recompile();
+    }
+}
+
+/// Number of samples kept per history ring buffer.
+const HISTORY_CAPACITY: usize = 120;
 
 struct SystemStats {
     pub cpu_usages: Vec<f32>,
     pub mem_usage: u64,
     pub max_mem: u64,
 
+    /// Rolling CPU usage history, one ring buffer per core.
+    cpu_history: Vec<VecDeque<f32>>,
+    /// Rolling RAM usage history as a percentage.
+    mem_history: VecDeque<f32>,
+    /// Seconds between samples, used to scale the history x-axis.
+    sample_interval: f64,
+
+    /// Per-mount disk usage from the most recent tick.
+    pub disks: Vec<DiskUsage>,
+    /// Hardware temperature readings from the most recent tick.
+    pub temps: Vec<TempReading>,
+    /// Rolling network receive/transmit rate history, in bytes per second.
+    rx_history: VecDeque<f32>,
+    tx_history: VecDeque<f32>,
+
     cpu_data: CPUData,
     mem_data: MemData,
+    disk_data: DiskData,
+    net_data: NetData,
+    temp_data: TempData,
 }
 
 impl SystemStats {
-    pub fn new() -> Self {
+    pub fn new(sample_interval: f64, temperature_type: TemperatureType) -> Self {
         Self {
             cpu_usages: Vec::new(),
             mem_usage: 0,
             max_mem: 0,
+            cpu_history: Vec::new(),
+            mem_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            sample_interval,
+            disks: Vec::new(),
+            temps: Vec::new(),
+            rx_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            tx_history: VecDeque::with_capacity(HISTORY_CAPACITY),
             cpu_data: CPUData::new(),
             mem_data: MemData::new(),
+            disk_data: DiskData::new(),
+            net_data: NetData::new(),
+            temp_data: TempData::new(temperature_type),
         }
     }
 
     pub fn update(&mut self) {
         self.cpu_usages = self.cpu_data.get_cpu_usage();
-    
+
         self.mem_usage = self.mem_data.get_used();
         self.max_mem = self.mem_data.get_total();
+
+        self.disks = self.disk_data.get_usage();
+        self.temps = self.temp_data.get_temperatures();
+
+        self.net_data.update();
+        push_sample(&mut self.rx_history, self.net_data.rx_rate as f32);
+        push_sample(&mut self.tx_history, self.net_data.tx_rate as f32);
+
+        // Grow the per-core history buffers lazily to match the core count.
+        if self.cpu_history.len() != self.cpu_usages.len() {
+            self.cpu_history
+                .resize_with(self.cpu_usages.len(), || VecDeque::with_capacity(HISTORY_CAPACITY));
+        }
+
+        for (history, usage) in self.cpu_history.iter_mut().zip(&self.cpu_usages) {
+            push_sample(history, *usage);
+        }
+
+        let mem_percent = if self.max_mem == 0 {
+            0.0
+        } else {
+            self.mem_usage as f32 / self.max_mem as f32 * 100.0
+        };
+        push_sample(&mut self.mem_history, mem_percent);
     }
 }
 
+/// Push a sample onto a history buffer, dropping the oldest once full.
+fn push_sample(history: &mut VecDeque<f32>, value: f32) {
+    if history.len() == HISTORY_CAPACITY {
+        history.pop_front();
+    }
+    history.push_back(value);
+}
+
+/// Recursively split `area` per the layout tree, collecting each leaf widget
+/// with its resolved rectangle.
+fn collect_layout(node: &LayoutNode, area: Rect, out: &mut Vec<(Widget, Rect)>) {
+    match node {
+        LayoutNode::Widget(widget) => out.push((*widget, area)),
+        LayoutNode::Rows(entries) => {
+            let constraints: Vec<Constraint> =
+                entries.iter().map(|entry| Constraint::Fill(entry.ratio)).collect();
+            let areas = Layout::vertical(constraints).split(area);
+            for (entry, child) in entries.iter().zip(areas.iter()) {
+                collect_layout(&entry.node, *child, out);
+            }
+        }
+        LayoutNode::Columns(entries) => {
+            let constraints: Vec<Constraint> =
+                entries.iter().map(|entry| Constraint::Fill(entry.ratio)).collect();
+            let areas = Layout::horizontal(constraints).split(area);
+            for (entry, child) in entries.iter().zip(areas.iter()) {
+                collect_layout(&entry.node, *child, out);
+            }
+        }
+    }
+}
+
+/// Build a fixed-width text gauge line like `CPU 0 [##########          ]  50%`.
+fn text_gauge(label: &str, percent: f32) -> String {
+    const WIDTH: usize = 20;
+    let filled = ((percent / 100.0) * WIDTH as f32).round() as usize;
+    let filled = filled.min(WIDTH);
+    let bar: String = "#".repeat(filled) + &" ".repeat(WIDTH - filled);
+    format!("{label} [{bar}] {percent:>3.0}%")
+}
+
+/// Wrapping next selection for a list of `len` rows, or `None` when empty.
+fn next_index(current: Option<usize>, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match current {
+        Some(i) if i >= len - 1 => 0,
+        Some(i) => i + 1,
+        None => 0,
+    })
+}
+
+/// Wrapping previous selection for a list of `len` rows, or `None` when empty.
+fn previous_index(current: Option<usize>, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match current {
+        Some(0) | None => len - 1,
+        Some(i) => i - 1,
+    })
+}
+
+/// Convert a history buffer into `(seconds, value)` points for a `Dataset`.
+fn history_points(history: &VecDeque<f32>, interval: f64) -> Vec<(f64, f64)> {
+    history
+        .iter()
+        .enumerate()
+        .map(|(i, value)| (i as f64 * interval, *value as f64))
+        .collect()
+}
+
 pub struct App {
-    cpu_data: CPUData,
     mem_data: MemData,
     should_exit: bool,
-    ips: [String; 3],
+    ips: Vec<String>,
     server_states: Vec<ServerState>,
     list_state: ListState,
     system_stats: SystemStats,
+    show_history: bool,
+    focus: Focus,
+    process_collector: ProcessCollector,
+    processes: Vec<ProcessData>,
+    process_state: TableState,
+    process_sorting: ProcessSorting,
+    process_sorting_reverse: bool,
+    pending_kill: Option<u32>,
+    search: SearchState,
+    basic: bool,
+    layout: LayoutNode,
+    config: Config,
 }
 
 impl App {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
+        let ips: Vec<String> = config
+            .servers
+            .iter()
+            .map(|server| server.label().to_string())
+            .collect();
+
         Self {
-            cpu_data: CPUData::new(),
             mem_data: MemData::new(),
             should_exit: false,
-            ips: [
-                String::from("129.80.58.106:8080"),
-                String::from("129.80.58.106:8081"),
-                String::from("129.80.58.106:8082"),
-            ],
+            ips,
             server_states: Vec::new(),
             list_state: ListState::default(),
-            system_stats: SystemStats::new(),
+            system_stats: SystemStats::new(
+                config.system_poll_ms as f64 / 1000.0,
+                config.temperature_type,
+            ),
+            show_history: config.history_graphs,
+            focus: Focus::Servers,
+            process_collector: ProcessCollector::new(),
+            processes: Vec::new(),
+            process_state: TableState::default(),
+            process_sorting: ProcessSorting::Cpu,
+            process_sorting_reverse: true,
+            pending_kill: None,
+            search: SearchState::new(),
+            basic: config.basic,
+            layout: config.layout(),
+            config,
         }
     }
 
     pub fn run(&mut self, mut terminal: DefaultTerminal) -> io::Result<()> {
         // Create server states
-        for ip in &self.ips {
-            let state: ServerState = ServerState::new(&ip);
+        for server in &self.config.servers {
+            let state: ServerState = ServerState::new(&server.address, &server.edition);
             self.server_states.push(state);
         }
 
         // Get system info
         self.system_stats.update();
+        self.refresh_processes();
 
         // Initial Draw
         terminal.draw(|frame| self.render(frame))?;
         self.list_state.select(Some(0));
+        self.process_state.select(Some(0));
+
+        let system_interval = Duration::from_millis(self.config.system_poll_ms);
+        let server_interval = Duration::from_millis(self.config.server_poll_ms);
 
         let mut system_update = Instant::now();
         let mut state_timer = Instant::now();
         while !self.should_exit {
             // Update Server Stats
-            if system_update.elapsed() >= Duration::from_millis(500) {
+            if system_update.elapsed() >= system_interval {
                 self.system_stats.update();
+                self.refresh_processes();
                 system_update = Instant::now();
             }
-            
+
             // Get server information
-            if state_timer.elapsed() >= Duration::from_millis(30000) {
+            if state_timer.elapsed() >= server_interval {
                 for state in &mut self.server_states {
                     match state.update() {
                         Ok(_) => (),
@@ -120,10 +405,44 @@ impl App {
         if event::poll(Duration::from_millis(16))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
+                    // A pending kill confirmation swallows every other key.
+                    if self.pending_kill.is_some() {
+                        match key.code {
+                            KeyCode::Char('y') => self.confirm_kill(),
+                            KeyCode::Char('n') | KeyCode::Esc => self.pending_kill = None,
+                            _ => (),
+                        }
+                        return Ok(());
+                    }
+
+                    // While searching, keystrokes edit the query instead of
+                    // driving the app.
+                    if self.search.active {
+                        match key.code {
+                            KeyCode::Esc => self.search.cancel(),
+                            KeyCode::Enter => self.search.active = false,
+                            KeyCode::Backspace => self.search.backspace(),
+                            KeyCode::Left => self.search.move_left(),
+                            KeyCode::Right => self.search.move_right(),
+                            KeyCode::Char(c) => self.search.insert(c),
+                            _ => (),
+                        }
+                        return Ok(());
+                    }
+
                     match key.code {
                         KeyCode::Char('q') => self.should_exit = true,
-                        KeyCode::Up => self.list_state_previous(),
-                        KeyCode::Down => self.list_state_next(),
+                        KeyCode::Char('/') => self.search.active = true,
+                        KeyCode::Char('g') => self.show_history = !self.show_history,
+                        KeyCode::Tab => self.toggle_focus(),
+                        KeyCode::Char('c') => self.set_process_sorting(ProcessSorting::Cpu),
+                        KeyCode::Char('m') => self.set_process_sorting(ProcessSorting::Mem),
+                        KeyCode::Char('r') => {
+                            self.process_sorting_reverse = !self.process_sorting_reverse
+                        }
+                        KeyCode::Char('k') => self.request_kill(),
+                        KeyCode::Up => self.select_previous(),
+                        KeyCode::Down => self.select_next(),
                         _ => ()
                     }
                 }
@@ -132,55 +451,298 @@ impl App {
         Ok(())
     }
 
-    fn list_state_next(&mut self) {
-        let i = match self.list_state.selected() {
-            Some(i) => {
-                if i >= self.ips.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
+    fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            Focus::Servers => Focus::Processes,
+            Focus::Processes => Focus::Servers,
+        };
+    }
+
+    fn select_next(&mut self) {
+        match self.focus {
+            Focus::Servers => {
+                let i = next_index(self.list_state.selected(), self.ips.len());
+                self.list_state.select(i);
+            }
+            Focus::Processes => {
+                let i = next_index(self.process_state.selected(), self.visible_processes().len());
+                self.process_state.select(i);
             }
-            None => 0
+        }
+    }
+
+    fn select_previous(&mut self) {
+        match self.focus {
+            Focus::Servers => {
+                let i = previous_index(self.list_state.selected(), self.ips.len());
+                self.list_state.select(i);
+            }
+            Focus::Processes => {
+                let i =
+                    previous_index(self.process_state.selected(), self.visible_processes().len());
+                self.process_state.select(i);
+            }
+        }
+    }
+
+    fn set_process_sorting(&mut self, sorting: ProcessSorting) {
+        self.process_sorting = sorting;
+        self.sort_processes();
+    }
+
+    /// Re-enumerate host processes, preserving the current sort order.
+    fn refresh_processes(&mut self) {
+        self.processes = self.process_collector.collect();
+        self.sort_processes();
+    }
+
+    fn sort_processes(&mut self) {
+        match self.process_sorting {
+            ProcessSorting::Cpu => self
+                .processes
+                .sort_by(|a, b| a.cpu.partial_cmp(&b.cpu).unwrap_or(std::cmp::Ordering::Equal)),
+            ProcessSorting::Mem => self.processes.sort_by(|a, b| a.mem.cmp(&b.mem)),
+        }
+        if self.process_sorting_reverse {
+            self.processes.reverse();
+        }
+    }
+
+    /// The process rows currently shown, after applying the search filter.
+    /// Selection, wrapping, and killing all index into this list so they stay
+    /// aligned with what the table renders.
+    fn visible_processes(&self) -> Vec<&ProcessData> {
+        self.processes
+            .iter()
+            .filter(|process| self.focus != Focus::Processes || self.search.matches(&process.name))
+            .collect()
+    }
+
+    /// Stage a SIGTERM confirmation for the currently selected process.
+    fn request_kill(&mut self) {
+        if self.focus != Focus::Processes {
+            return;
+        }
+        let visible = self.visible_processes();
+        if let Some(process) = self.process_state.selected().and_then(|i| visible.get(i)) {
+            self.pending_kill = Some(process.pid);
+        }
+    }
+
+    fn confirm_kill(&mut self) {
+        if let Some(pid) = self.pending_kill.take() {
+            let _ = self.process_collector.kill(pid);
+            self.refresh_processes();
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
+        // Reserve a bottom row for the search box while it is open.
+        let body = if self.search.active {
+            let [body, search] = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(3),
+            ])
+            .areas(frame.area());
+            self.render_search_box(frame, search);
+            body
+        } else {
+            frame.area()
         };
-        self.list_state.select(Some(i));
+
+        if self.basic {
+            self.render_basic(frame, body);
+        } else {
+            // Walk the configured layout tree and render each placed widget.
+            let layout = self.layout.clone();
+            let mut placements: Vec<(Widget, Rect)> = Vec::new();
+            collect_layout(&layout, body, &mut placements);
+            for (widget, area) in placements {
+                self.render_widget(frame, widget, area);
+            }
+        }
+
+        if self.pending_kill.is_some() {
+            self.render_kill_prompt(frame);
+        }
     }
 
-    fn list_state_previous(&mut self) {
-        let i = match self.list_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.ips.len() - 1
+    /// Render a single layout leaf into its resolved area.
+    fn render_widget(&mut self, frame: &mut Frame, widget: Widget, area: Rect) {
+        match widget {
+            Widget::System => {
+                if self.show_history {
+                    self.cpu_history_chart(frame, area);
                 } else {
-                    i - 1
+                    frame.render_widget(self.cpu_chart(), area);
                 }
             }
-            None => 0
+            Widget::Servers => {
+                let mut state = self.list_state.clone();
+                frame.render_stateful_widget(self.player_list(), area, &mut state);
+            }
+            Widget::Details => {
+                frame.render_widget(self.server_details(), area);
+            }
+            Widget::Processes => {
+                let mut state = self.process_state.clone();
+                frame.render_stateful_widget(self.process_table(), area, &mut state);
+            }
+            Widget::Disk => {
+                frame.render_widget(self.disk_table(), area);
+            }
+            Widget::Net => {
+                self.net_history_chart(frame, area);
+            }
+            Widget::Temp => {
+                frame.render_widget(self.temp_list(), area);
+            }
+        }
+    }
+
+    /// Compact, graph-free layout for low terminal heights and SSH sessions:
+    /// CPU/RAM as text gauges above a single condensed server summary.
+    fn render_basic(&mut self, frame: &mut Frame, area: Rect) {
+        // One line per core, one for RAM, plus the two borders.
+        let gauge_height = self.system_stats.cpu_usages.len() as u16 + 1 + 2;
+
+        let [gauges, summary] = Layout::vertical([
+            Constraint::Length(gauge_height),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        frame.render_widget(self.text_gauges(), gauges);
+        frame.render_widget(self.server_summary(), summary);
+    }
+
+    /// Compact text gauges for each CPU core and RAM, e.g. `CPU 0 [##########          ]  50%`.
+    fn text_gauges(&self) -> Paragraph {
+        let mut lines: Vec<Line> = Vec::new();
+
+        for (i, usage) in self.system_stats.cpu_usages.iter().enumerate() {
+            lines.push(Line::from(text_gauge(&format!("CPU {i}"), *usage)));
+        }
+
+        let mem_percent = if self.system_stats.max_mem == 0 {
+            0.0
+        } else {
+            self.system_stats.mem_usage as f32 / self.system_stats.max_mem as f32 * 100.0
         };
-        self.list_state.select(Some(i));
+        lines.push(Line::from(text_gauge("RAM  ", mem_percent)));
+
+        Paragraph::new(lines).block(Block::bordered().title("System"))
     }
 
-    fn render(&mut self, frame: &mut Frame) {
-        // Add one for RAM, 2 for top and bottom border
-        let count = self.cpu_data.cpu_count + 1 + 2;
+    /// One condensed status line per server for the basic view.
+    fn server_summary(&self) -> Paragraph {
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, state) in self.server_states.iter().enumerate() {
+            let status = if state.status { "online" } else { "offline" };
+            lines.push(Line::from(format!(
+                "{} - {} {}/{}",
+                self.ips[i], status, state.player_count, state.max_players,
+            )));
+        }
+        Paragraph::new(lines).block(Block::bordered().title("Servers"))
+    }
 
-        let vertical = Layout::vertical([
-            Constraint::Length(count as u16),
-            Constraint::Fill(1)
-        ]);
-        let horizontal = Layout::horizontal([
-            Constraint::Percentage(20),
-            Constraint::Percentage(80),
-        ]);
+    /// Render the search input, dimming an empty query and reddening the
+    /// border for a pattern that failed to compile.
+    fn render_search_box(&self, frame: &mut Frame, area: Rect) {
+        let target = match self.focus {
+            Focus::Servers => "players",
+            Focus::Processes => "processes",
+        };
+
+        let border_style = if self.search.is_invalid_search {
+            Style::new().fg(Color::Red)
+        } else {
+            Style::new()
+        };
+
+        let block = Block::bordered()
+            .title(format!("Search {target}"))
+            .border_style(border_style);
+
+        let paragraph = if self.search.is_blank_search {
+            Paragraph::new(Line::from("type a pattern...").dim()).block(block)
+        } else {
+            Paragraph::new(Line::from(self.search.query.as_str())).block(block)
+        };
+
+        frame.render_widget(paragraph, area);
+
+        // Place the terminal cursor at the query's cursor offset (inside the border).
+        let cursor_x = area.x + 1 + self.search.cursor as u16;
+        frame.set_cursor_position((cursor_x, area.y + 1));
+    }
+
+    fn process_table(&self) -> Table {
+        let rows: Vec<Row> = self
+            .visible_processes()
+            .iter()
+            .map(|process| {
+                Row::new(vec![
+                    process.pid.to_string(),
+                    process.name.clone(),
+                    format!("{:.1}", process.cpu),
+                    self.mem_data.bytes_to_string(process.mem),
+                ])
+            })
+            .collect();
+
+        let sort = match self.process_sorting {
+            ProcessSorting::Cpu => "CPU%",
+            ProcessSorting::Mem => "Mem",
+        };
+        let arrow = if self.process_sorting_reverse { "v" } else { "^" };
+        let title = format!("Processes (sort: {sort} {arrow})");
+
+        let widths = [
+            Constraint::Length(8),
+            Constraint::Fill(1),
+            Constraint::Length(6),
+            Constraint::Length(10),
+        ];
+        let style: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+        Table::new(rows, widths)
+            .header(Row::new(vec!["PID", "Name", "CPU%", "Mem"]).bold())
+            .block(self.focus_block(title, Focus::Processes))
+            .row_highlight_style(style)
+            .highlight_symbol(">> ")
+    }
 
-        let [system_view, process_view] = vertical.areas(frame.area());
-        let [process_view, process_details] = horizontal.areas(process_view);
-    
-        let mut state = self.list_state.clone();
+    /// Centered confirmation box shown while a kill awaits `y`/`n`.
+    fn render_kill_prompt(&self, frame: &mut Frame) {
+        let Some(pid) = self.pending_kill else { return };
+
+        let area = frame.area();
+        let width = 40.min(area.width);
+        let height = 3.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let text = format!("Send SIGTERM to PID {pid}? (y/n)");
+        let paragraph = Paragraph::new(Line::from(text).centered())
+            .block(Block::bordered().title("Confirm Kill"));
 
-        frame.render_widget(self.cpu_chart(), system_view);
-        frame.render_stateful_widget(self.player_list(), process_view, &mut state);
-        frame.render_widget(self.server_details(), process_details);
+        frame.render_widget(Clear, popup);
+        frame.render_widget(paragraph, popup);
+    }
+
+    /// Bordered block whose title is highlighted when the pane is focused.
+    fn focus_block(&self, title: String, pane: Focus) -> Block {
+        let block = Block::bordered().title(title);
+        if self.focus == pane {
+            block.border_style(Style::new().fg(BLUE.c400))
+        } else {
+            block
+        }
     }
 
     fn server_details(&mut self) -> Paragraph {
@@ -188,7 +750,13 @@ impl App {
             Some(i) => i,
             None => 0,
         };
-        let state = &self.server_states[index];
+        let state = match self.server_states.get(index) {
+            Some(state) => state,
+            None => {
+                return Paragraph::new(Line::from("No servers configured"))
+                    .block(Block::bordered().title("Server Info"));
+            }
+        };
 
         let mut lines: Vec<Line> = Vec::new();
 
@@ -206,6 +774,9 @@ impl App {
         lines.push(Line::from(player_count_line));
         lines.push(Line::from("Players:"));
         for player in &state.players {
+            if self.focus == Focus::Servers && !self.search.matches(player) {
+                continue;
+            }
             lines.push(Line::from(format!("\t{}", player)));
         }
 
@@ -214,13 +785,6 @@ impl App {
     }
 
     fn player_list(&mut self) -> List {
-        for state in &mut self.server_states {
-            match state.update() {
-                Ok(_) => (),
-                Err(_) => continue,
-            }
-        }
-
         let mut list_items: Vec<ListItem> = Vec::new();
         for i in 0..self.server_states.len() {
             let item: ListItem = ListItem::from(
@@ -231,7 +795,7 @@ impl App {
         
         let style: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
         List::new(list_items)
-            .block(Block::bordered().title("Player Counts"))
+            .block(self.focus_block(String::from("Player Counts"), Focus::Servers))
             .highlight_style(style)
             .highlight_symbol(">> ")
             .highlight_spacing(ratatui::widgets::HighlightSpacing::Always)
@@ -278,6 +842,164 @@ impl App {
             .direction(Direction::Horizontal)
     }
 
+    fn cpu_history_chart(&self, frame: &mut Frame, area: Rect) {
+        let stats = &self.system_stats;
+        let interval = stats.sample_interval;
+
+        // Colors cycled across the per-core datasets; RAM gets its own.
+        const CORE_COLORS: [Color; 6] = [
+            Color::Cyan,
+            Color::Magenta,
+            Color::Yellow,
+            Color::Green,
+            Color::Blue,
+            Color::Red,
+        ];
+
+        // Collect owned points first so the datasets can borrow them.
+        let mut series: Vec<Vec<(f64, f64)>> = Vec::new();
+        for history in &stats.cpu_history {
+            series.push(history_points(history, interval));
+        }
+        let mem_points = history_points(&stats.mem_history, interval);
+
+        let mut datasets: Vec<Dataset> = Vec::new();
+        for (i, points) in series.iter().enumerate() {
+            datasets.push(
+                Dataset::default()
+                    .name(format!("CPU {i}"))
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::new().fg(CORE_COLORS[i % CORE_COLORS.len()]))
+                    .data(points),
+            );
+        }
+        datasets.push(
+            Dataset::default()
+                .name("RAM")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::new().fg(SLATE.c200))
+                .data(&mem_points),
+        );
+
+        // Widest buffer determines the visible time window.
+        let samples = stats
+            .cpu_history
+            .iter()
+            .map(VecDeque::len)
+            .max()
+            .unwrap_or(0)
+            .max(stats.mem_history.len());
+        let x_max = (samples.saturating_sub(1)) as f64 * interval;
+
+        let chart = Chart::new(datasets)
+            .block(Block::bordered().title("System"))
+            .x_axis(
+                Axis::default()
+                    .style(Style::new().fg(Color::Gray))
+                    .bounds([0.0, x_max.max(1.0)])
+                    .labels([format!("-{x_max:.0}s"), String::from("now")]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::new().fg(Color::Gray))
+                    .bounds([0.0, 100.0])
+                    .labels(["0", "50", "100"]),
+            );
+
+        frame.render_widget(chart, area);
+    }
+
+    fn net_history_chart(&self, frame: &mut Frame, area: Rect) {
+        let stats = &self.system_stats;
+        let interval = stats.sample_interval;
+
+        let rx_points = history_points(&stats.rx_history, interval);
+        let tx_points = history_points(&stats.tx_history, interval);
+
+        let datasets = vec![
+            Dataset::default()
+                .name("RX")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::new().fg(GREEN.c400))
+                .data(&rx_points),
+            Dataset::default()
+                .name("TX")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::new().fg(BLUE.c400))
+                .data(&tx_points),
+        ];
+
+        let samples = stats.rx_history.len().max(stats.tx_history.len());
+        let x_max = (samples.saturating_sub(1)) as f64 * interval;
+
+        // Scale the y-axis to the busiest sample seen in the window.
+        let y_max = stats
+            .rx_history
+            .iter()
+            .chain(stats.tx_history.iter())
+            .copied()
+            .fold(1.0_f32, f32::max) as f64;
+        let rate_label = self.mem_data.bytes_to_string(y_max as u64);
+
+        let chart = Chart::new(datasets)
+            .block(Block::bordered().title("Network"))
+            .x_axis(
+                Axis::default()
+                    .style(Style::new().fg(Color::Gray))
+                    .bounds([0.0, x_max.max(1.0)]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::new().fg(Color::Gray))
+                    .bounds([0.0, y_max])
+                    .labels(["0", &format!("{rate_label}/s")]),
+            );
+
+        frame.render_widget(chart, area);
+    }
+
+    fn disk_table(&self) -> Table {
+        let rows: Vec<Row> = self
+            .system_stats
+            .disks
+            .iter()
+            .map(|disk| {
+                Row::new(vec![
+                    disk.mount.clone(),
+                    self.mem_data.bytes_to_string(disk.used),
+                    self.mem_data.bytes_to_string(disk.available),
+                    self.mem_data.bytes_to_string(disk.total),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ];
+        Table::new(rows, widths)
+            .header(Row::new(vec!["Mount", "Used", "Avail", "Total"]).bold())
+            .block(Block::bordered().title("Disk"))
+    }
+
+    fn temp_list(&self) -> Paragraph {
+        let unit = self.config.temperature_type.suffix();
+        let lines: Vec<Line> = self
+            .system_stats
+            .temps
+            .iter()
+            .map(|temp| Line::from(format!("{}: {:.1}{unit}", temp.label, temp.value)))
+            .collect();
+
+        Paragraph::new(lines).block(Block::bordered().title("Temperatures"))
+    }
+
     fn horizontal_bar(&self, label: String, value: f32) -> Bar {
         let style = self.bar_color(value);
         Bar::default()
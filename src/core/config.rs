@@ -0,0 +1,262 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+
+use crate::core::temp::TemperatureType;
+
+/// A single monitored server as described in the config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfig {
+    /// Address the status API is queried against, e.g. `1.2.3.4:8080`.
+    pub address: String,
+    /// Optional friendly name shown in the list instead of the raw address.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Minecraft edition to query (`java` or `bedrock`).
+    #[serde(default = "default_edition")]
+    pub edition: String,
+}
+
+impl ServerConfig {
+    /// Label used in the UI: the display name when set, otherwise the address.
+    pub fn label(&self) -> &str {
+        match &self.name {
+            Some(name) => name,
+            None => &self.address,
+        }
+    }
+}
+
+fn default_edition() -> String {
+    String::from("java")
+}
+
+fn default_system_poll_ms() -> u64 {
+    500
+}
+
+fn default_server_poll_ms() -> u64 {
+    30000
+}
+
+/// A widget that can be placed in the configurable layout.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Widget {
+    /// CPU/RAM system view (bars or history graphs).
+    System,
+    /// Server list with player counts.
+    Servers,
+    /// Details for the selected server.
+    Details,
+    /// Host process table.
+    Processes,
+    /// Per-mount disk usage table.
+    Disk,
+    /// Network throughput history.
+    Net,
+    /// Hardware temperature readings.
+    Temp,
+}
+
+/// A node in the layout tree: a vertical or horizontal split of weighted
+/// children, or a leaf placing a single widget. `render` walks this rather
+/// than assuming a fixed two-row arrangement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutNode {
+    Rows(Vec<LayoutEntry>),
+    Columns(Vec<LayoutEntry>),
+    Widget(Widget),
+}
+
+/// One child within a split, carrying its relative size weight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutEntry {
+    #[serde(default = "default_ratio")]
+    pub ratio: u16,
+    pub node: LayoutNode,
+}
+
+fn default_ratio() -> u16 {
+    1
+}
+
+impl LayoutEntry {
+    fn new(ratio: u16, node: LayoutNode) -> Self {
+        Self { ratio, node }
+    }
+}
+
+/// The built-in layout: the system view beside the network graph on top, the
+/// server list with details and processes in the middle, and disk and
+/// temperatures along the bottom.
+pub fn default_layout() -> LayoutNode {
+    LayoutNode::Rows(vec![
+        LayoutEntry::new(
+            1,
+            LayoutNode::Columns(vec![
+                LayoutEntry::new(1, LayoutNode::Widget(Widget::System)),
+                LayoutEntry::new(1, LayoutNode::Widget(Widget::Net)),
+            ]),
+        ),
+        LayoutEntry::new(
+            3,
+            LayoutNode::Columns(vec![
+                LayoutEntry::new(1, LayoutNode::Widget(Widget::Servers)),
+                LayoutEntry::new(
+                    4,
+                    LayoutNode::Rows(vec![
+                        LayoutEntry::new(1, LayoutNode::Widget(Widget::Details)),
+                        LayoutEntry::new(1, LayoutNode::Widget(Widget::Processes)),
+                    ]),
+                ),
+            ]),
+        ),
+        LayoutEntry::new(
+            1,
+            LayoutNode::Columns(vec![
+                LayoutEntry::new(1, LayoutNode::Widget(Widget::Disk)),
+                LayoutEntry::new(1, LayoutNode::Widget(Widget::Temp)),
+            ]),
+        ),
+    ])
+}
+
+/// Top-level `craig.toml` contents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Servers to monitor, in display order.
+    pub servers: Vec<ServerConfig>,
+    /// How often system stats (CPU/RAM) are sampled, in milliseconds.
+    pub system_poll_ms: u64,
+    /// How often server status is refreshed, in milliseconds.
+    pub server_poll_ms: u64,
+    /// Start with the braille history graphs instead of the instantaneous bars.
+    pub history_graphs: bool,
+    /// Start in the compact, graph-free basic mode.
+    pub basic: bool,
+    /// Optional custom widget layout; falls back to [`default_layout`].
+    pub layout: Option<LayoutNode>,
+    /// Unit used for temperature readings.
+    pub temperature_type: TemperatureType,
+}
+
+impl Config {
+    /// The configured layout, or the built-in default when none is set.
+    pub fn layout(&self) -> LayoutNode {
+        self.layout.clone().unwrap_or_else(default_layout)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            servers: vec![
+                ServerConfig {
+                    address: String::from("129.80.58.106:8080"),
+                    name: None,
+                    edition: default_edition(),
+                },
+                ServerConfig {
+                    address: String::from("129.80.58.106:8081"),
+                    name: None,
+                    edition: default_edition(),
+                },
+                ServerConfig {
+                    address: String::from("129.80.58.106:8082"),
+                    name: None,
+                    edition: default_edition(),
+                },
+            ],
+            system_poll_ms: default_system_poll_ms(),
+            server_poll_ms: default_server_poll_ms(),
+            history_graphs: false,
+            basic: false,
+            layout: None,
+            temperature_type: TemperatureType::default(),
+        }
+    }
+}
+
+/// Commented template written when no config file exists yet.
+const TEMPLATE: &str = "\
+# craig configuration
+#
+# Root-level settings must come before the [[servers]] array-of-tables below,
+# otherwise TOML binds them to the last server entry instead of the document
+# root.
+
+# How often system stats (CPU/RAM) are sampled, in milliseconds.
+system_poll_ms = 500
+
+# How often server status is refreshed, in milliseconds.
+server_poll_ms = 30000
+
+# Start with the braille history graphs instead of the instantaneous bars.
+history_graphs = false
+
+# Start in the compact, graph-free basic mode (same as passing --basic).
+basic = false
+
+# Unit for temperature readings: \"celsius\", \"fahrenheit\", or \"kelvin\".
+temperature_type = \"celsius\"
+
+# The widget layout is configurable via a [layout] tree of row/column splits;
+# when omitted craig uses its built-in arrangement.
+
+# Each [[servers]] entry is a monitored Minecraft server. `name` is optional
+# and `edition` may be \"java\" (default) or \"bedrock\".
+[[servers]]
+address = \"129.80.58.106:8080\"
+# name = \"Survival\"
+# edition = \"java\"
+
+[[servers]]
+address = \"129.80.58.106:8081\"
+
+[[servers]]
+address = \"129.80.58.106:8082\"
+";
+
+/// Default config file location inside the platform config directory.
+pub fn default_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("craig").join("craig.toml"))
+}
+
+impl Config {
+    /// Load the config from `path`, falling back to `default_path()` when none
+    /// is given. A missing file is created from a commented template and the
+    /// defaults are returned; an unreadable or malformed file also falls back
+    /// to the defaults without being overwritten.
+    pub fn load(path: Option<PathBuf>) -> Self {
+        let path = match path.or_else(default_path) {
+            Some(path) => path,
+            None => return Self::default(),
+        };
+
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            let _ = fs::write(&path, TEMPLATE);
+            return Self::default();
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => match toml::from_str(&contents) {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("craig: failed to parse {}: {err}", path.display());
+                    Self::default()
+                }
+            },
+            Err(err) => {
+                eprintln!("craig: failed to read {}: {err}", path.display());
+                Self::default()
+            }
+        }
+    }
+}
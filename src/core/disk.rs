@@ -0,0 +1,41 @@
+use psutil::disk;
+
+/// Usage figures for a single mounted filesystem.
+pub struct DiskUsage {
+    pub mount: String,
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+}
+
+pub struct DiskData {}
+
+impl DiskData {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Collect per-mount usage for every physical partition.
+    pub fn get_usage(&self) -> Vec<DiskUsage> {
+        let mut disks: Vec<DiskUsage> = Vec::new();
+
+        let partitions = match disk::partitions_physical() {
+            Ok(partitions) => partitions,
+            Err(_) => return disks,
+        };
+
+        for partition in partitions {
+            let mount = partition.mountpoint();
+            if let Ok(usage) = disk::disk_usage(mount) {
+                disks.push(DiskUsage {
+                    mount: mount.display().to_string(),
+                    total: usage.total(),
+                    used: usage.used(),
+                    available: usage.free(),
+                });
+            }
+        }
+
+        disks
+    }
+}
@@ -0,0 +1,9 @@
+pub mod app;
+pub mod config;
+pub mod cpu;
+pub mod disk;
+pub mod mem;
+pub mod net;
+pub mod process;
+pub mod server;
+pub mod temp;
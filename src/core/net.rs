@@ -0,0 +1,54 @@
+use std::time::Instant;
+
+use psutil::network::NetIoCountersCollector;
+
+/// Tracks network throughput by diffing the cumulative byte counters between
+/// two [`update`](NetData::update) ticks and dividing by elapsed time.
+pub struct NetData {
+    collector: NetIoCountersCollector,
+    prev_rx: u64,
+    prev_tx: u64,
+    last: Instant,
+
+    /// Receive rate in bytes per second from the most recent tick.
+    pub rx_rate: f64,
+    /// Transmit rate in bytes per second from the most recent tick.
+    pub tx_rate: f64,
+}
+
+impl NetData {
+    pub fn new() -> Self {
+        let mut collector = NetIoCountersCollector::default();
+        let (prev_rx, prev_tx) = match collector.net_io_counters() {
+            Ok(counters) => (counters.bytes_recv(), counters.bytes_sent()),
+            Err(_) => (0, 0),
+        };
+
+        Self {
+            collector,
+            prev_rx,
+            prev_tx,
+            last: Instant::now(),
+            rx_rate: 0.0,
+            tx_rate: 0.0,
+        }
+    }
+
+    pub fn update(&mut self) {
+        let counters = match self.collector.net_io_counters() {
+            Ok(counters) => counters,
+            Err(_) => return,
+        };
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        if elapsed > 0.0 {
+            self.rx_rate = counters.bytes_recv().saturating_sub(self.prev_rx) as f64 / elapsed;
+            self.tx_rate = counters.bytes_sent().saturating_sub(self.prev_tx) as f64 / elapsed;
+        }
+
+        self.prev_rx = counters.bytes_recv();
+        self.prev_tx = counters.bytes_sent();
+        self.last = now;
+    }
+}
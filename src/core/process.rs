@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use psutil::process::{processes, Process};
+
+/// A single row in the process table.
+pub struct ProcessData {
+    pub pid: u32,
+    pub name: String,
+    pub cpu: f32,
+    pub mem: u64,
+}
+
+/// Column the process table is ordered by.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSorting {
+    Cpu,
+    Mem,
+}
+
+/// Enumerates host processes, keeping a handle per pid between ticks so
+/// `cpu_percent` reports usage relative to the previous sample.
+pub struct ProcessCollector {
+    processes: HashMap<u32, Process>,
+}
+
+impl ProcessCollector {
+    pub fn new() -> Self {
+        Self {
+            processes: HashMap::new(),
+        }
+    }
+
+    /// Sample every live process into a fresh `Vec<ProcessData>`, pruning any
+    /// handles whose process has since exited.
+    pub fn collect(&mut self) -> Vec<ProcessData> {
+        let current = match processes() {
+            Ok(current) => current,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut data: Vec<ProcessData> = Vec::new();
+        let mut alive: Vec<u32> = Vec::new();
+        for process in current.into_iter().flatten() {
+            let pid = process.pid();
+            alive.push(pid);
+
+            let entry = self.processes.entry(pid).or_insert(process);
+            let cpu = entry.cpu_percent().unwrap_or(0.0);
+            let name = entry.name().unwrap_or_default();
+            let mem = entry.memory_info().map(|info| info.rss()).unwrap_or(0);
+
+            data.push(ProcessData { pid, name, cpu, mem });
+        }
+
+        self.processes.retain(|pid, _| alive.contains(pid));
+        data
+    }
+
+    /// Send SIGTERM to `pid`.
+    pub fn kill(&self, pid: u32) -> Result<(), Box<dyn Error>> {
+        let process = Process::new(pid)?;
+        process.terminate()?;
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use ureq;
 
 pub struct ServerState {
     pub ip: String,
+    pub edition: String,
     pub status: bool,
     pub player_count: u64,
     pub max_players: u64,
@@ -13,9 +14,10 @@ pub struct ServerState {
 }
 
 impl ServerState {
-    pub fn new(ip: &str) -> Self {
+    pub fn new(ip: &str, edition: &str) -> Self {
         Self {
             ip: String::from(ip),
+            edition: String::from(edition),
             status: false,
             player_count: 0,
             max_players: 0,
@@ -25,8 +27,9 @@ impl ServerState {
 
     pub fn update(&mut self) -> Result<(), Box<dyn Error>> {
         let ip: &str = &self.ip;
+        let edition: &str = &self.edition;
         let response = ureq::get(
-                format!("https://api.mcstatus.io/v2/status/java/{ip}")
+                format!("https://api.mcstatus.io/v2/status/{edition}/{ip}")
             )
             .call()?
             .body_mut()
@@ -0,0 +1,71 @@
+use psutil::sensors;
+use serde::{Serialize, Deserialize};
+
+/// Unit hardware temperatures are reported in, mirroring bottom's
+/// `TemperatureType`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemperatureType {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl Default for TemperatureType {
+    fn default() -> Self {
+        TemperatureType::Celsius
+    }
+}
+
+impl TemperatureType {
+    /// Short unit marker for display, e.g. `°C`/`°F`/`K`.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            TemperatureType::Celsius => "°C",
+            TemperatureType::Fahrenheit => "°F",
+            TemperatureType::Kelvin => "K",
+        }
+    }
+}
+
+/// A single temperature sensor reading in the configured unit.
+pub struct TempReading {
+    pub label: String,
+    pub value: f64,
+}
+
+pub struct TempData {
+    unit: TemperatureType,
+}
+
+impl TempData {
+    pub fn new(unit: TemperatureType) -> Self {
+        Self { unit }
+    }
+
+    /// Read every available sensor component into the configured unit.
+    pub fn get_temperatures(&self) -> Vec<TempReading> {
+        let mut readings: Vec<TempReading> = Vec::new();
+
+        for sensor in sensors::temperatures().into_iter().flatten() {
+            let label = match sensor.label() {
+                Some(label) => format!("{} {label}", sensor.unit()),
+                None => sensor.unit().to_string(),
+            };
+            readings.push(TempReading {
+                label,
+                value: self.convert(sensor.current().celsius()),
+            });
+        }
+
+        readings
+    }
+
+    fn convert(&self, celsius: f64) -> f64 {
+        match self.unit {
+            TemperatureType::Celsius => celsius,
+            TemperatureType::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureType::Kelvin => celsius + 273.15,
+        }
+    }
+}
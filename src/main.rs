@@ -1,11 +1,20 @@
 use std::io;
+use std::path::PathBuf;
 
 pub mod core;
 use crate::core::app::App;
+use crate::core::config::Config;
 
 fn main() -> io::Result<()> {
+    let mut config = Config::load(config_arg());
+
+    // Flags take precedence over the config file.
+    if basic_flag() {
+        config.basic = true;
+    }
+
     let terminal = ratatui::init();
-    let mut app = App::new();
+    let mut app = App::new(config);
 
     app.run(terminal)?;
 
@@ -13,3 +22,22 @@ fn main() -> io::Result<()> {
 
     Ok(())
 }
+
+/// Pull an explicit config path from `-C`/`--config`, if supplied.
+fn config_arg() -> Option<PathBuf> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-C" | "--config" => return args.next().map(PathBuf::from),
+            _ => continue,
+        }
+    }
+    None
+}
+
+/// Whether `-b`/`--basic` was passed to start in compact mode.
+fn basic_flag() -> bool {
+    std::env::args()
+        .skip(1)
+        .any(|arg| arg == "-b" || arg == "--basic")
+}